@@ -0,0 +1,101 @@
+//! Capturing a [`Backtrace`] at the point a `Result` is inspected, gated behind the
+//! `backtrace` feature.
+//!
+//! Most hand-written or macro-generated error types carry no backtrace of their own,
+//! yet the point where the `Result` surfaces is exactly where one is most useful for
+//! diagnosis. This captures one there, regardless of whether `E` provides one.
+
+use std::backtrace::Backtrace;
+
+/// Captures a [`Backtrace`] at the call site and hands it, along with the error, to
+/// `f`.
+pub trait InspectErrorWithBacktrace<E> {
+    /// If the `Result` is `Err`, captures a [`Backtrace::capture`] (respecting
+    /// `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`) and calls `f` with the error and the
+    /// captured backtrace, then returns the `Result` unchanged.
+    ///
+    /// ```
+    /// use inspect_error::InspectErrorWithBacktrace;
+    ///
+    /// let output: Result<i32, _> = Err("couldn't connect to the database")
+    ///     .inspect_error_with_backtrace(|err, backtrace| {
+    ///         eprintln!("{err}\n{backtrace}");
+    ///     });
+    ///
+    /// assert_eq!(output, Err("couldn't connect to the database"));
+    /// ```
+    #[must_use]
+    fn inspect_error_with_backtrace(self, f: impl FnOnce(&E, &Backtrace)) -> Self;
+}
+
+impl<T, E> InspectErrorWithBacktrace<E> for Result<T, E> {
+    fn inspect_error_with_backtrace(self, f: impl FnOnce(&E, &Backtrace)) -> Self {
+        if let Err(ref error) = self {
+            let backtrace = Backtrace::capture();
+            f(error, &backtrace);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InspectErrorWithBacktrace;
+    use std::backtrace::BacktraceStatus;
+
+    /// Sets an environment variable for the lifetime of the guard, restoring
+    /// whatever value (if any) it held beforehand on drop.
+    struct EnvVarGuard {
+        key: &'static str,
+        previous: Option<String>,
+    }
+
+    impl EnvVarGuard {
+        fn set(key: &'static str, value: &str) -> Self {
+            let previous = std::env::var(key).ok();
+            std::env::set_var(key, value);
+            Self { key, previous }
+        }
+    }
+
+    impl Drop for EnvVarGuard {
+        fn drop(&mut self) {
+            match &self.previous {
+                Some(value) => std::env::set_var(self.key, value),
+                None => std::env::remove_var(self.key),
+            }
+        }
+    }
+
+    #[test]
+    fn closure_is_called_with_error_and_captured_backtrace() {
+        // `Backtrace::capture()` only actually captures frames when asked to; without
+        // this, a disabled backtrace's `Display` is still the non-empty string
+        // "disabled backtrace", so asserting on non-emptiness alone would pass without
+        // ever exercising the capture. The guard restores the prior value on drop, but
+        // `std` caches the outcome of the first capture in the process, so every test
+        // here that captures sets the variable the same way.
+        let _guard = EnvVarGuard::set("RUST_LIB_BACKTRACE", "1");
+
+        let error_code = 42;
+        let mut observed = None;
+
+        let _: Result<i32, _> = Err(error_code).inspect_error_with_backtrace(|error, backtrace| {
+            observed = Some((*error, backtrace.status()));
+        });
+
+        let (error, status) = observed.expect("closure should have run");
+        assert_eq!(error, error_code);
+        assert_eq!(status, BacktraceStatus::Captured);
+    }
+
+    #[test]
+    fn result_is_returned_unchanged() {
+        let _guard = EnvVarGuard::set("RUST_LIB_BACKTRACE", "1");
+        let error_code = 42;
+        let result: Result<i32, _> =
+            Err(error_code).inspect_error_with_backtrace(|_, _| ());
+        assert_eq!(result, Err(error_code));
+    }
+}
@@ -0,0 +1,210 @@
+//! Inspecting the full [`Error::source`] chain, not just the outermost error.
+
+use std::error::Error;
+
+/// Caps how many links of a `source()` chain are walked, in case a buggy
+/// implementation cycles back on itself.
+const MAX_CHAIN_DEPTH: usize = 32;
+
+/// Walks a `Result`'s error and its full [`Error::source`] chain.
+pub trait InspectErrorChain<E> {
+    /// Calls `f` once per error in the chain, starting at the outermost error (index
+    /// `0`) and following `source()` links until one returns `None`, then returns the
+    /// `Result` unchanged.
+    ///
+    /// The walk stops early after `MAX_CHAIN_DEPTH` links, in case a buggy
+    /// `source()` implementation cycles.
+    ///
+    /// ```
+    /// use inspect_error::InspectErrorChain;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct NotFound;
+    ///
+    /// impl fmt::Display for NotFound {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "No such file or directory")
+    ///     }
+    /// }
+    ///
+    /// impl Error for NotFound {}
+    ///
+    /// let mut messages = Vec::new();
+    /// let output: Result<(), _> = Err(NotFound)
+    ///     .inspect_error_chain(|index, error| messages.push(format!("{index}: {error}")));
+    ///
+    /// assert_eq!(messages, vec!["0: No such file or directory"]);
+    /// assert!(output.is_err());
+    /// ```
+    #[must_use]
+    fn inspect_error_chain(self, f: impl FnMut(usize, &(dyn Error + 'static))) -> Self;
+
+    /// Renders the error and its full `source()` chain as a single, anyhow-style line,
+    /// with each cause joined by `": "` (e.g. `"failed to read instrs: No such file or
+    /// directory"`).
+    ///
+    /// Returns `None` if the `Result` is `Ok`.
+    ///
+    /// ```
+    /// use inspect_error::InspectErrorChain;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct NotFound;
+    ///
+    /// impl fmt::Display for NotFound {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "No such file or directory")
+    ///     }
+    /// }
+    ///
+    /// impl Error for NotFound {}
+    ///
+    /// let output: Result<(), _> = Err(NotFound);
+    /// assert_eq!(output.display_error_chain().as_deref(), Some("No such file or directory"));
+    /// ```
+    #[must_use]
+    fn display_error_chain(&self) -> Option<String>;
+}
+
+impl<T, E: Error + 'static> InspectErrorChain<E> for Result<T, E> {
+    fn inspect_error_chain(self, mut f: impl FnMut(usize, &(dyn Error + 'static))) -> Self {
+        if let Err(ref error) = self {
+            for (index, error) in chain(error).enumerate() {
+                f(index, error);
+            }
+        }
+
+        self
+    }
+
+    fn display_error_chain(&self) -> Option<String> {
+        let error = self.as_ref().err()?;
+        let rendered = chain(error)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(": ");
+
+        Some(rendered)
+    }
+}
+
+/// Yields `error` and then each of its `source()`s, outermost first, stopping after
+/// [`MAX_CHAIN_DEPTH`] links.
+fn chain<'a>(error: &'a (dyn Error + 'static)) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    let mut next = Some(error);
+
+    std::iter::from_fn(move || {
+        let error = next.take()?;
+        next = error.source();
+        Some(error)
+    })
+    .take(MAX_CHAIN_DEPTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InspectErrorChain;
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Layered {
+        message: &'static str,
+        source: Option<Box<Layered>>,
+    }
+
+    impl fmt::Display for Layered {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.message)
+        }
+    }
+
+    impl Error for Layered {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.source
+                .as_deref()
+                .map(|source| source as &(dyn Error + 'static))
+        }
+    }
+
+    fn sample_error() -> Layered {
+        Layered {
+            message: "failed to read instrs",
+            source: Some(Box::new(Layered {
+                message: "No such file or directory",
+                source: None,
+            })),
+        }
+    }
+
+    #[test]
+    fn chain_is_walked_outermost_first() {
+        let mut messages = Vec::new();
+        let result: Result<(), _> =
+            Err(sample_error()).inspect_error_chain(|_, error| messages.push(error.to_string()));
+
+        assert_eq!(
+            messages,
+            vec!["failed to read instrs", "No such file or directory"]
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn chain_is_displayed_like_anyhow() {
+        let result: Result<(), _> = Err(sample_error());
+
+        assert_eq!(
+            result.display_error_chain().as_deref(),
+            Some("failed to read instrs: No such file or directory")
+        );
+    }
+
+    #[test]
+    fn ok_has_no_displayed_chain() {
+        let result: Result<(), Layered> = Ok(());
+        assert_eq!(result.display_error_chain(), None);
+    }
+
+    /// An error whose `source()` always returns itself, modeling a buggy
+    /// implementation that cycles instead of terminating.
+    #[derive(Debug)]
+    struct Cyclic;
+
+    impl fmt::Display for Cyclic {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "cyclic error")
+        }
+    }
+
+    impl Error for Cyclic {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(self)
+        }
+    }
+
+    #[test]
+    fn inspect_error_chain_stops_after_max_depth_on_a_cycle() {
+        let mut count = 0;
+        let result: Result<(), _> =
+            Err(Cyclic).inspect_error_chain(|_, _| count += 1);
+
+        assert_eq!(count, super::MAX_CHAIN_DEPTH);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn display_error_chain_stops_after_max_depth_on_a_cycle() {
+        let result: Result<(), _> = Err(Cyclic);
+        let rendered = result.display_error_chain().expect("should be Err");
+
+        assert_eq!(
+            rendered.matches("cyclic error").count(),
+            super::MAX_CHAIN_DEPTH
+        );
+    }
+}
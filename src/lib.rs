@@ -40,6 +40,40 @@
 //! let output = read_magic_number_from_db();
 //! assert_eq!(output, Err("couldn't connect to the database"));
 //! ```
+//!
+//! # Feature flags
+//!
+//! - `log`: adds `log_error!`, `warn_error!`, `trace_error!`, `debug_error!`, and
+//!   `log_error_with!`, which log the error through the `log` facade instead of
+//!   calling a closure. These are macros, not trait methods, so the emitted record is
+//!   attributed to the call site rather than to this crate.
+//! - `tracing`: adds `error_event!`, `warn_event!`, `trace_event!`, `debug_event!`,
+//!   and `error_event_with!`, the same idea for the `tracing` facade.
+//! - `backtrace`: adds `InspectErrorWithBacktrace`, which captures a
+//!   [`std::backtrace::Backtrace`] at the inspection point, regardless of whether the
+//!   error itself carries one.
+
+mod chain;
+pub use crate::chain::InspectErrorChain;
+
+mod option;
+pub use crate::option::InspectOption;
+
+mod sink;
+pub use crate::sink::{CapturingSink, InspectErrorTo, InspectSink, NullSink, StderrSink, StdoutSink};
+
+#[cfg(feature = "log")]
+#[doc(hidden)]
+pub mod log_ext;
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub mod tracing_ext;
+
+#[cfg(feature = "backtrace")]
+mod backtrace;
+#[cfg(feature = "backtrace")]
+pub use crate::backtrace::InspectErrorWithBacktrace;
 
 /// The core trait of this crate. Implemented for `Result`.
 pub trait InspectError<E> {
@@ -47,6 +81,14 @@ pub trait InspectError<E> {
     fn inspect_error(self, inspect: impl FnOnce(&E)) -> Self;
 }
 
+/// The success-path counterpart to [`InspectError`]. A separate trait so that
+/// [`InspectError<E>`]'s generics stay untouched for downstream code that names it in
+/// a bound or impl.
+pub trait InspectOk<T> {
+    #[must_use]
+    fn inspect_ok(self, inspect: impl FnOnce(&T)) -> Self;
+}
+
 impl<T, E> InspectError<E> for Result<T, E> {
     /// Call the provided closure with a reference to the contained error (*if* the
     /// `Result` is an error) and then return the `Result`. Mainly intended for
@@ -78,6 +120,26 @@ impl<T, E> InspectError<E> for Result<T, E> {
     }
 }
 
+impl<T, E> InspectOk<T> for Result<T, E> {
+    /// Call the provided closure with a reference to the contained value (*if* the
+    /// `Result` is `Ok`) and then return the `Result` unchanged. The success-path
+    /// equivalent of [`InspectError::inspect_error`].
+    ///
+    /// ```
+    /// use inspect_error::InspectOk;
+    ///
+    /// let output: Result<i32, &str> = Ok(42).inspect_ok(|value| println!("Got {value}."));
+    /// assert_eq!(output, Ok(42));
+    /// ```
+    fn inspect_ok(self, inspect: impl FnOnce(&T)) -> Self {
+        if let Ok(ref value) = self {
+            (inspect)(value);
+        }
+
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::InspectError;
@@ -102,3 +164,26 @@ mod tests {
         assert_eq!(result, Err(error_code));
     }
 }
+
+#[cfg(test)]
+mod ok_tests {
+    use super::InspectOk;
+
+    #[test]
+    fn closure_is_called() {
+        let magic_number = 42;
+        let mut output = None;
+
+        let _: Result<_, &str> = Ok(magic_number).inspect_ok(|value| {
+            output = Some(*value);
+        });
+
+        assert_eq!(output, Some(magic_number));
+    }
+
+    #[test]
+    fn errors_are_left_untouched() {
+        let result: Result<i32, _> = Err("nope").inspect_ok(|_| panic!("should not be called"));
+        assert_eq!(result, Err("nope"));
+    }
+}
@@ -0,0 +1,157 @@
+//! Declarative macros that log a `Result`'s error through the [`log`] facade, gated
+//! behind the `log` feature.
+//!
+//! A trait method can't attribute a log record to its caller: `file!()`,
+//! `line!()`, and `module_path!()` all resolve at the point they're textually
+//! written, so calling `log::log!` from inside this crate would always report
+//! *this crate's* location. A macro doesn't have that problem, because it expands
+//! into the caller's source before those are evaluated.
+
+// Only referenced through `$crate::log_ext::log` inside the macros below, which are
+// invoked from other crates — `cargo build` alone never sees a use of it.
+#[doc(hidden)]
+#[allow(unused_imports)]
+pub use log;
+
+/// Shared expansion for the level-specific macros below. Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __inspect_error_log_at {
+    ($level:expr, $result:expr) => {{
+        let result = $result;
+        if let Err(ref error) = result {
+            $crate::log_ext::log::log!($level, "{error}");
+        }
+        result
+    }};
+    ($level:expr, $result:expr, $msg:expr) => {{
+        let result = $result;
+        if let Err(ref error) = result {
+            let msg = $msg;
+            $crate::log_ext::log::log!($level, "{msg}: {error}");
+        }
+        result
+    }};
+}
+
+/// Logs a `Result`'s error at [`log::Level::Error`], attributed to the call site,
+/// then evaluates to the `Result` unchanged.
+///
+/// ```
+/// use inspect_error::log_error;
+///
+/// let output: Result<i32, &str> = log_error!(Err("couldn't connect to the database"));
+/// assert_eq!(output, Err("couldn't connect to the database"));
+/// ```
+#[macro_export]
+macro_rules! log_error {
+    ($result:expr) => {
+        $crate::__inspect_error_log_at!($crate::log_ext::log::Level::Error, $result)
+    };
+}
+
+/// Logs a `Result`'s error at [`log::Level::Warn`], attributed to the call site, then
+/// evaluates to the `Result` unchanged.
+#[macro_export]
+macro_rules! warn_error {
+    ($result:expr) => {
+        $crate::__inspect_error_log_at!($crate::log_ext::log::Level::Warn, $result)
+    };
+}
+
+/// Logs a `Result`'s error at [`log::Level::Trace`], attributed to the call site,
+/// then evaluates to the `Result` unchanged.
+#[macro_export]
+macro_rules! trace_error {
+    ($result:expr) => {
+        $crate::__inspect_error_log_at!($crate::log_ext::log::Level::Trace, $result)
+    };
+}
+
+/// Logs a `Result`'s error at [`log::Level::Debug`], attributed to the call site,
+/// then evaluates to the `Result` unchanged.
+#[macro_export]
+macro_rules! debug_error {
+    ($result:expr) => {
+        $crate::__inspect_error_log_at!($crate::log_ext::log::Level::Debug, $result)
+    };
+}
+
+/// Logs a `Result`'s error at [`log::Level::Error`] as `"{msg}: {err}"`, attributed to
+/// the call site, then evaluates to the `Result` unchanged.
+///
+/// ```
+/// use inspect_error::log_error_with;
+///
+/// let output: Result<i32, &str> =
+///     log_error_with!(Err("not found"), "failed to read config");
+/// assert_eq!(output, Err("not found"));
+/// ```
+#[macro_export]
+macro_rules! log_error_with {
+    ($result:expr, $msg:expr) => {
+        $crate::__inspect_error_log_at!($crate::log_ext::log::Level::Error, $result, $msg)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, OnceLock};
+
+    struct RecordingLogger {
+        records: Mutex<Vec<(log::Level, String)>>,
+    }
+
+    impl log::Log for RecordingLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.records
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    fn recording_logger() -> &'static RecordingLogger {
+        static LOGGER: OnceLock<&'static RecordingLogger> = OnceLock::new();
+        LOGGER.get_or_init(|| {
+            let logger: &'static RecordingLogger = Box::leak(Box::new(RecordingLogger {
+                records: Mutex::new(Vec::new()),
+            }));
+            let _ = log::set_logger(logger);
+            log::set_max_level(log::LevelFilter::Trace);
+            logger
+        })
+    }
+
+    #[test]
+    fn error_is_reported_at_the_requested_level_and_result_is_unchanged() {
+        let logger = recording_logger();
+        logger.records.lock().unwrap().clear();
+
+        let first: Result<i32, _> = log_error!(Err("boom"));
+        let second: Result<i32, _> = warn_error!(Err("boom"));
+        let third: Result<i32, _> = log_error_with!(Err("boom"), "failed to read config");
+        let untouched: Result<i32, &str> = log_error!(Ok(42));
+
+        assert_eq!(first, Err("boom"));
+        assert_eq!(second, Err("boom"));
+        assert_eq!(third, Err("boom"));
+        assert_eq!(untouched, Ok(42));
+
+        let records = logger.records.lock().unwrap();
+        assert_eq!(
+            *records,
+            vec![
+                (log::Level::Error, "boom".to_string()),
+                (log::Level::Warn, "boom".to_string()),
+                (log::Level::Error, "failed to read config: boom".to_string()),
+            ]
+        );
+    }
+}
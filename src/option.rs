@@ -0,0 +1,85 @@
+//! The [`Option`] counterpart to [`InspectError`](crate::InspectError).
+
+/// Symmetric, side-effecting peek operations for `Option`, mirroring
+/// [`InspectError`](crate::InspectError) for `Result`.
+pub trait InspectOption<T> {
+    /// Call the provided closure with a reference to the contained value (*if* the
+    /// `Option` is `Some`) and then return the `Option` unchanged.
+    ///
+    /// ```
+    /// use inspect_error::InspectOption;
+    ///
+    /// let output = Some(42).inspect_some(|value| println!("Got {value}."));
+    /// assert_eq!(output, Some(42));
+    /// ```
+    #[must_use]
+    fn inspect_some(self, inspect: impl FnOnce(&T)) -> Self;
+
+    /// Call the provided closure (*if* the `Option` is `None`) and then return the
+    /// `Option` unchanged.
+    ///
+    /// ```
+    /// use inspect_error::InspectOption;
+    ///
+    /// let output: Option<i32> = None.inspect_none(|| println!("Got nothing."));
+    /// assert_eq!(output, None);
+    /// ```
+    #[must_use]
+    fn inspect_none(self, inspect: impl FnOnce()) -> Self;
+}
+
+impl<T> InspectOption<T> for Option<T> {
+    fn inspect_some(self, inspect: impl FnOnce(&T)) -> Self {
+        if let Some(ref value) = self {
+            (inspect)(value);
+        }
+
+        self
+    }
+
+    fn inspect_none(self, inspect: impl FnOnce()) -> Self {
+        if self.is_none() {
+            (inspect)();
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InspectOption;
+
+    #[test]
+    fn inspect_some_closure_is_called() {
+        let magic_number = 42;
+        let mut output = None;
+
+        let _ = Some(magic_number).inspect_some(|value| {
+            output = Some(*value);
+        });
+
+        assert_eq!(output, Some(magic_number));
+    }
+
+    #[test]
+    fn inspect_some_leaves_none_untouched() {
+        let option: Option<i32> = None.inspect_some(|_| panic!("should not be called"));
+        assert_eq!(option, None);
+    }
+
+    #[test]
+    fn inspect_none_closure_is_called() {
+        let mut called = false;
+        let option: Option<i32> = None.inspect_none(|| called = true);
+
+        assert!(called);
+        assert_eq!(option, None);
+    }
+
+    #[test]
+    fn inspect_none_leaves_some_untouched() {
+        let option = Some(42).inspect_none(|| panic!("should not be called"));
+        assert_eq!(option, Some(42));
+    }
+}
@@ -0,0 +1,191 @@
+//! A pluggable sink for error inspection, so applications can route every
+//! `inspect_error_to` call through one configurable object instead of hard-coding
+//! `eprintln!` in every closure — and swap in a capturing sink in tests.
+
+use std::cell::RefCell;
+
+/// Something that can receive and report an inspected error.
+///
+/// ```
+/// use inspect_error::InspectSink;
+///
+/// struct CountingSink(std::cell::Cell<usize>);
+///
+/// impl<E> InspectSink<E> for CountingSink {
+///     fn report(&self, _err: &E) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let sink = CountingSink(std::cell::Cell::new(0));
+/// sink.report(&"couldn't connect to the database");
+/// assert_eq!(sink.0.get(), 1);
+/// ```
+pub trait InspectSink<E> {
+    /// Reports `err`.
+    fn report(&self, err: &E);
+}
+
+/// Reports errors by printing their `Display` to stderr.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StderrSink;
+
+impl<E: std::fmt::Display> InspectSink<E> for StderrSink {
+    fn report(&self, err: &E) {
+        eprintln!("{err}");
+    }
+}
+
+/// Reports errors by printing their `Display` to stdout.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdoutSink;
+
+impl<E: std::fmt::Display> InspectSink<E> for StdoutSink {
+    fn report(&self, err: &E) {
+        println!("{err}");
+    }
+}
+
+/// Discards every error reported to it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullSink;
+
+impl<E> InspectSink<E> for NullSink {
+    fn report(&self, _err: &E) {}
+}
+
+/// Records every reported error into a `RefCell<Vec<E>>`, so tests can assert on what
+/// was observed.
+///
+/// ```
+/// use inspect_error::{CapturingSink, InspectErrorTo};
+///
+/// let sink = CapturingSink::new();
+/// let output: Result<i32, _> = Err("couldn't connect to the database").inspect_error_to(&sink);
+///
+/// assert_eq!(sink.captured(), vec!["couldn't connect to the database"]);
+/// assert_eq!(output, Err("couldn't connect to the database"));
+/// ```
+#[derive(Debug)]
+pub struct CapturingSink<E> {
+    captured: RefCell<Vec<E>>,
+}
+
+impl<E> Default for CapturingSink<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> CapturingSink<E> {
+    /// Creates an empty `CapturingSink`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            captured: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a clone of every error reported so far, in report order.
+    #[must_use]
+    pub fn captured(&self) -> Vec<E>
+    where
+        E: Clone,
+    {
+        self.captured.borrow().clone()
+    }
+
+    /// Returns how many errors have been reported so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.captured.borrow().len()
+    }
+
+    /// Returns `true` if no error has been reported yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.captured.borrow().is_empty()
+    }
+}
+
+impl<E: Clone> InspectSink<E> for CapturingSink<E> {
+    fn report(&self, err: &E) {
+        self.captured.borrow_mut().push(err.clone());
+    }
+}
+
+/// Inspects a `Result`'s error through a pluggable [`InspectSink`].
+pub trait InspectErrorTo<E> {
+    /// If the `Result` is `Err`, reports the error to `sink`, then returns the
+    /// `Result` unchanged.
+    ///
+    /// ```
+    /// use inspect_error::{InspectErrorTo, StderrSink};
+    ///
+    /// let output: Result<i32, _> =
+    ///     Err("couldn't connect to the database").inspect_error_to(&StderrSink);
+    ///
+    /// assert_eq!(output, Err("couldn't connect to the database"));
+    /// ```
+    #[must_use]
+    fn inspect_error_to(self, sink: &impl InspectSink<E>) -> Self;
+}
+
+impl<T, E> InspectErrorTo<E> for Result<T, E> {
+    fn inspect_error_to(self, sink: &impl InspectSink<E>) -> Self {
+        if let Err(ref error) = self {
+            sink.report(error);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CapturingSink, InspectErrorTo, InspectSink, NullSink};
+
+    #[test]
+    fn capturing_sink_observes_error_exactly_once() {
+        let sink = CapturingSink::new();
+
+        let result: Result<i32, _> =
+            Err("couldn't connect to the database").inspect_error_to(&sink);
+
+        assert_eq!(sink.len(), 1);
+        assert_eq!(sink.captured(), vec!["couldn't connect to the database"]);
+        assert_eq!(result, Err("couldn't connect to the database"));
+    }
+
+    #[test]
+    fn capturing_sink_ignores_ok() {
+        let sink = CapturingSink::new();
+
+        let result: Result<i32, &str> = Ok(42).inspect_error_to(&sink);
+
+        assert!(sink.is_empty());
+        assert_eq!(result, Ok(42));
+    }
+
+    #[test]
+    fn null_sink_discards_errors() {
+        let result: Result<i32, _> = Err("ignored").inspect_error_to(&NullSink);
+        assert_eq!(result, Err("ignored"));
+    }
+
+    #[test]
+    fn custom_sink_can_be_plugged_in() {
+        struct CountingSink(std::cell::Cell<usize>);
+
+        impl InspectSink<&str> for CountingSink {
+            fn report(&self, _err: &&str) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let sink = CountingSink(std::cell::Cell::new(0));
+        let _: Result<i32, _> = Err("boom").inspect_error_to(&sink);
+
+        assert_eq!(sink.0.get(), 1);
+    }
+}
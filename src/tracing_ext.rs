@@ -0,0 +1,183 @@
+//! Declarative macros that emit a `Result`'s error as a [`tracing`] event, gated
+//! behind the `tracing` feature.
+//!
+//! `tracing`'s level macros bake their call-site metadata into a static callsite
+//! record at the point they're written, so calling them from inside this crate would
+//! always attribute the event to *this crate*. A macro doesn't have that problem,
+//! because it expands into the caller's source first.
+//!
+//! These are named distinctly from the `log`-facade macros (`error_event!` rather than
+//! `log_error!`) so that both features can be enabled at once without a clash, and so
+//! the names don't imply a level (`Level::TRACE`) that only the `trace_event!` macro
+//! actually uses.
+
+// Only referenced through `$crate::tracing_ext::tracing` inside the macros below,
+// which are invoked from other crates — `cargo build` alone never sees a use of it.
+#[doc(hidden)]
+#[allow(unused_imports)]
+pub use tracing;
+
+/// Emits a [`tracing::error!`] event with the error, attributed to the call site,
+/// then evaluates to the `Result` unchanged.
+///
+/// ```
+/// use inspect_error::error_event;
+///
+/// let output: Result<i32, &str> = error_event!(Err("couldn't connect to the database"));
+/// assert_eq!(output, Err("couldn't connect to the database"));
+/// ```
+#[macro_export]
+macro_rules! error_event {
+    ($result:expr) => {{
+        let result = $result;
+        if let Err(ref error) = result {
+            $crate::tracing_ext::tracing::error!("{error}");
+        }
+        result
+    }};
+}
+
+/// Emits a [`tracing::warn!`] event with the error, attributed to the call site, then
+/// evaluates to the `Result` unchanged.
+#[macro_export]
+macro_rules! warn_event {
+    ($result:expr) => {{
+        let result = $result;
+        if let Err(ref error) = result {
+            $crate::tracing_ext::tracing::warn!("{error}");
+        }
+        result
+    }};
+}
+
+/// Emits a [`tracing::trace!`] event with the error, attributed to the call site,
+/// then evaluates to the `Result` unchanged.
+#[macro_export]
+macro_rules! trace_event {
+    ($result:expr) => {{
+        let result = $result;
+        if let Err(ref error) = result {
+            $crate::tracing_ext::tracing::trace!("{error}");
+        }
+        result
+    }};
+}
+
+/// Emits a [`tracing::debug!`] event with the error, attributed to the call site,
+/// then evaluates to the `Result` unchanged.
+#[macro_export]
+macro_rules! debug_event {
+    ($result:expr) => {{
+        let result = $result;
+        if let Err(ref error) = result {
+            $crate::tracing_ext::tracing::debug!("{error}");
+        }
+        result
+    }};
+}
+
+/// Emits a [`tracing::error!`] event rendered as `"{msg}: {err}"`, attributed to the
+/// call site, then evaluates to the `Result` unchanged.
+///
+/// ```
+/// use inspect_error::error_event_with;
+///
+/// let output: Result<i32, &str> =
+///     error_event_with!(Err("not found"), "failed to read config");
+/// assert_eq!(output, Err("not found"));
+/// ```
+#[macro_export]
+macro_rules! error_event_with {
+    ($result:expr, $msg:expr) => {{
+        let result = $result;
+        if let Err(ref error) = result {
+            let msg = $msg;
+            $crate::tracing_ext::tracing::error!("{msg}: {error}");
+        }
+        result
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    #[derive(Clone, Default)]
+    struct RecordingSubscriber {
+        events: Arc<Mutex<Vec<(tracing::Level, String)>>>,
+    }
+
+    struct MessageVisitor(Option<String>);
+
+    impl Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.0 = Some(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut visitor = MessageVisitor(None);
+            event.record(&mut visitor);
+
+            self.events.lock().unwrap().push((
+                *event.metadata().level(),
+                visitor.0.unwrap_or_default(),
+            ));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn error_is_reported_at_the_requested_level_and_result_is_unchanged() {
+        let subscriber = RecordingSubscriber::default();
+        let events = Arc::clone(&subscriber.events);
+
+        let (first, second, third, untouched) = tracing::subscriber::with_default(subscriber, || {
+            let first: Result<i32, _> = error_event!(Err("boom"));
+            let second: Result<i32, _> = warn_event!(Err("boom"));
+            let third: Result<i32, _> = error_event_with!(Err("boom"), "failed to read config");
+            let untouched: Result<i32, &str> = error_event!(Ok(42));
+
+            (first, second, third, untouched)
+        });
+
+        assert_eq!(first, Err("boom"));
+        assert_eq!(second, Err("boom"));
+        assert_eq!(third, Err("boom"));
+        assert_eq!(untouched, Ok(42));
+
+        let events = events.lock().unwrap();
+        assert_eq!(
+            *events,
+            vec![
+                (tracing::Level::ERROR, "boom".to_string()),
+                (tracing::Level::WARN, "boom".to_string()),
+                (
+                    tracing::Level::ERROR,
+                    "failed to read config: boom".to_string()
+                ),
+            ]
+        );
+    }
+}